@@ -3,6 +3,7 @@
 
 #[cfg(feature = "defmt")]
 use defmt::{debug, Format};
+use embedded_hal_async::digital::Wait;
 use embedded_hal_async::spi::Operation;
 use embedded_hal_async::spi::SpiDevice;
 use tartan_bitfield::bitfield;
@@ -14,11 +15,29 @@ use tartan_bitfield::Bitfield;
 pub enum SpiCommand {
     Reset = 0x06,
     Start = 0x08,
+    RData = 0x10,
     WriteReg = 0x40,
     ReadReg = 0x20,
 }
 
+/// Errors surfaced by the driver. The SPI bus and the (optional) DRDY input pin
+/// can fail independently, and with write verification enabled a register
+/// read-back that disagrees with the intended value yields [`Error::Verify`].
+#[derive(Debug)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub enum Error<SpiE, PinE = core::convert::Infallible> {
+    Spi(SpiE),
+    Pin(PinE),
+    /// A verified write read back a value other than the one written.
+    Verify {
+        addr: RegisterAddr,
+        expected: u8,
+        got: u8,
+    },
+}
+
 #[repr(u8)]
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "defmt", derive(Format))]
 pub enum RegisterAddr {
     Reg0 = 0x00,
@@ -67,6 +86,38 @@ bitfield! {
     }
 }
 
+/// Ties a configuration register bitfield type to its address on the device so
+/// it can be read, mutated and written through the generic
+/// [`update_reg`](ADS1220::update_reg) helper instead of a bespoke
+/// read-modify-write pair per register.
+pub trait Register {
+    const ADDRESS: RegisterAddr;
+
+    fn from_bits(bits: u8) -> Self;
+    fn bits(&self) -> u8;
+}
+
+macro_rules! impl_register {
+    ($reg:ident, $addr:expr) => {
+        impl Register for $reg {
+            const ADDRESS: RegisterAddr = $addr;
+
+            fn from_bits(bits: u8) -> Self {
+                $reg(bits)
+            }
+
+            fn bits(&self) -> u8 {
+                self.value()
+            }
+        }
+    };
+}
+
+impl_register!(Config0Reg, RegisterAddr::Reg0);
+impl_register!(Config1Reg, RegisterAddr::Reg1);
+impl_register!(Config2Reg, RegisterAddr::Reg2);
+impl_register!(Config3Reg, RegisterAddr::Reg3);
+
 #[repr(u8)]
 #[derive(Debug, num_enum::FromPrimitive, num_enum::IntoPrimitive)]
 #[cfg_attr(feature = "defmt", derive(Format))]
@@ -109,7 +160,7 @@ pub enum FIRRejectionFilter {
 }
 
 #[repr(u8)]
-#[derive(Debug, num_enum::FromPrimitive, num_enum::IntoPrimitive)]
+#[derive(Debug, Clone, Copy, num_enum::FromPrimitive, num_enum::IntoPrimitive)]
 #[cfg_attr(feature = "defmt", derive(Format))]
 pub enum VrefSelect {
     #[default]
@@ -144,7 +195,7 @@ pub enum DataRate {
 }
 
 #[repr(u8)]
-#[derive(Debug, num_enum::FromPrimitive, num_enum::IntoPrimitive)]
+#[derive(Debug, Clone, Copy, num_enum::FromPrimitive, num_enum::IntoPrimitive)]
 #[cfg_attr(feature = "defmt", derive(Format))]
 pub enum PgaGain {
     #[default]
@@ -175,49 +226,216 @@ pub enum AdcInputMux {
     Ain1AVss = 9,
     Ain2AVss = 10,
     Ain3AVss = 11,
-    Ain0SingleEnded = 12,
-    Ain1SingleEnded = 13,
-    Ain2SingleEnded = 14,
-    Ain3SingleEnded = 15,
+    // (AVDD + AVSS)/2 with both inputs internally shorted, used for offset
+    // calibration. Codes 13..=15 are reserved per the datasheet.
+    ShortedMidSupply = 12,
+    Reserved13 = 13,
+    Reserved14 = 14,
+    Reserved15 = 15,
 }
 
-pub struct ADS1220<SPI: SpiDevice> {
+/// An in-memory accumulation of all four configuration registers, applied to
+/// the device as a single coherent multi-byte WREG transaction by
+/// [`apply`](ADS1220::apply). Building the configuration off-device avoids the
+/// read-modify-write round trips of the individual setters and the briefly
+/// invalid intermediate states they can expose.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "defmt", derive(Format))]
+pub struct Config {
+    pub reg0: Config0Reg,
+    pub reg1: Config1Reg,
+    pub reg2: Config2Reg,
+    pub reg3: Config3Reg,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        // Power-on defaults, matching the register writes performed by `begin`.
+        Config {
+            reg0: Config0Reg(0x00),
+            reg1: Config1Reg(0x04),
+            reg2: Config2Reg(0x10),
+            reg3: Config3Reg(0x00),
+        }
+    }
+}
+
+impl Config {
+    pub fn mux(mut self, mux: AdcInputMux) -> Self {
+        self.reg0.set_mux(mux);
+        self
+    }
+
+    pub fn gain(mut self, gain: PgaGain) -> Self {
+        self.reg0.set_gain(gain);
+        self
+    }
+
+    pub fn data_rate(mut self, data_rate: DataRate) -> Self {
+        self.reg1.set_data_rate(data_rate);
+        self
+    }
+
+    pub fn operating_mode(mut self, mode: OperatingMode) -> Self {
+        self.reg1.set_operating_mode(mode);
+        self
+    }
+
+    pub fn vref(mut self, vref: VrefSelect) -> Self {
+        self.reg2.set_vref_selection(vref);
+        self
+    }
+
+    pub fn idac_current(mut self, idac_current: IDacSourceCurrent) -> Self {
+        self.reg2.set_idac_current_setting(idac_current);
+        self
+    }
+}
+
+pub struct ADS1220<SPI: SpiDevice, DRDY = ()> {
     spi: SPI,
+    // Optional DRDY input. `()` for polling-only builds that don't wire the pin.
+    drdy: DRDY,
+    // Cached scaling state, kept in sync with the config registers so raw codes
+    // can be turned into input voltages without re-reading the device.
+    vref: VrefSelect,
+    gain: PgaGain,
+    // Reference voltages the chip cannot know about: the value applied on the
+    // external REFP/REFN pins and the analog supply used when Vref = AVDD.
+    external_vref: f32,
+    analog_supply: f32,
+    // When set, every register write is read back and compared to catch SPI
+    // corruption on long/noisy buses (the ADS1220 has no hardware CRC).
+    verify_writes: bool,
+    // System offset (in raw codes) subtracted from every conversion, as found
+    // by `calibrate_offset` or restored via `set_offset`.
+    offset: i32,
 }
 
-impl<SPI: SpiDevice> ADS1220<SPI> {
+impl<SPI: SpiDevice> ADS1220<SPI, ()> {
     pub fn new(spi: SPI) -> Self {
-        ADS1220 { spi }
+        ADS1220 {
+            spi,
+            drdy: (),
+            vref: VrefSelect::Internal2p048,
+            gain: PgaGain::Factor1,
+            external_vref: 2.048,
+            analog_supply: 3.3,
+            verify_writes: false,
+            offset: 0,
+        }
+    }
+}
+
+impl<SPI: SpiDevice, DRDY: Wait> ADS1220<SPI, DRDY> {
+    /// Like [`new`](ADS1220::new) but also wires the DRDY input, enabling the
+    /// [`begin_continuous`](ADS1220::begin_continuous)/[`next_sample`](ADS1220::next_sample)
+    /// conversion stream.
+    pub fn new_with_drdy(spi: SPI, drdy: DRDY) -> Self {
+        ADS1220 {
+            spi,
+            drdy,
+            vref: VrefSelect::Internal2p048,
+            gain: PgaGain::Factor1,
+            external_vref: 2.048,
+            analog_supply: 3.3,
+            verify_writes: false,
+            offset: 0,
+        }
+    }
+
+    /// Puts the device in continuous-conversion mode and issues a single START,
+    /// after which each falling edge of DRDY signals a new sample to be read
+    /// with [`next_sample`](ADS1220::next_sample).
+    pub async fn begin_continuous(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.set_conv_mode_continuous().await?;
+        self.start_conv().await.map_err(Error::Spi)
+    }
+
+    /// Awaits the next DRDY falling edge, then clocks out the conversion result
+    /// with an RDATA read and returns the 24-bit signed code.
+    pub async fn next_sample(&mut self) -> Result<i32, Error<SPI::Error, DRDY::Error>> {
+        self.drdy
+            .wait_for_falling_edge()
+            .await
+            .map_err(Error::Pin)?;
+
+        let mut buf: [u8; 3] = [0x00; 3];
+        let read_cmd = [SpiCommand::RData as u8];
+        let mut operations = [
+            Operation::DelayNs(50),
+            Operation::Write(&read_cmd),
+            Operation::Read(&mut buf),
+        ];
+        self.spi
+            .transaction(&mut operations)
+            .await
+            .map_err(Error::Spi)?;
+        Ok(self.data_to_int(buf))
+    }
+}
+
+impl<SPI: SpiDevice, DRDY> ADS1220<SPI, DRDY> {
+
+    /// Sets the voltage present on the external REFP/REFN pins, used when the
+    /// reference is switched to [`VrefSelect::ExternalRef0`]/[`VrefSelect::ExternalRef1`].
+    pub fn set_external_vref(&mut self, volts: f32) {
+        self.external_vref = volts;
+    }
+
+    /// Sets the analog supply voltage (AVDD), used as the reference when
+    /// [`VrefSelect::AnalogSupply`] is selected.
+    pub fn set_analog_supply(&mut self, volts: f32) {
+        self.analog_supply = volts;
+    }
+
+    /// The reference voltage in volts for the currently cached [`VrefSelect`].
+    fn vref_volts(&self) -> f32 {
+        match self.vref {
+            VrefSelect::Internal2p048 => 2.048,
+            VrefSelect::ExternalRef0 | VrefSelect::ExternalRef1 => self.external_vref,
+            VrefSelect::AnalogSupply => self.analog_supply,
+        }
+    }
+
+    /// Enables or disables write verification. When enabled, every register
+    /// write is immediately read back and compared against the intended value,
+    /// surfacing [`Error::Verify`] on mismatch — the practical equivalent of a
+    /// hardware CRC for noisy SPI runs.
+    pub fn set_verify_writes(&mut self, verify: bool) {
+        self.verify_writes = verify;
     }
 
     async fn _write_register(
         &mut self,
         address: RegisterAddr,
         value: u8,
-    ) -> Result<(), SPI::Error> {
+    ) -> Result<(), Error<SPI::Error>> {
         let write_op = [SpiCommand::WriteReg as u8 | ((address as u8) << 2), value];
         // defmt::info!("{:?}", write_op);
         let mut operations = [Operation::DelayNs(50), Operation::Write(&write_op)];
-        self.spi.transaction(&mut operations).await
-    }
-
-    async fn write_register_0(&mut self, config: Config0Reg) -> Result<(), SPI::Error> {
-        self._write_register(RegisterAddr::Reg0, config.value())
+        self.spi
+            .transaction(&mut operations)
             .await
+            .map_err(Error::Spi)?;
+
+        if self.verify_writes {
+            let got = self._read_register(address).await.map_err(Error::Spi)?;
+            if got != value {
+                return Err(Error::Verify {
+                    addr: address,
+                    expected: value,
+                    got,
+                });
+            }
+        }
+        Ok(())
     }
 
-    async fn write_register_1(&mut self, config: Config1Reg) -> Result<(), SPI::Error> {
+    async fn write_register_1(&mut self, config: Config1Reg) -> Result<(), Error<SPI::Error>> {
         self._write_register(RegisterAddr::Reg1, config.value())
             .await
     }
-    async fn write_register_2(&mut self, config: Config2Reg) -> Result<(), SPI::Error> {
-        self._write_register(RegisterAddr::Reg2, config.value())
-            .await
-    }
-    async fn write_register_3(&mut self, config: Config3Reg) -> Result<(), SPI::Error> {
-        self._write_register(RegisterAddr::Reg3, config.value())
-            .await
-    }
 
     async fn _read_register(&mut self, register: RegisterAddr) -> Result<u8, SPI::Error> {
         let mut result: [u8; 1] = [0x00];
@@ -247,11 +465,32 @@ impl<SPI: SpiDevice> ADS1220<SPI> {
         Ok(Config3Reg(self._read_register(RegisterAddr::Reg3).await?))
     }
 
-    pub async fn begin(&mut self) -> Result<(), SPI::Error> {
-        self.reset().await?;
+    /// Reads a register, applies `f` to its typed bitfield and writes it back in
+    /// a single round trip. Generic over [`Register`] so several fields of one
+    /// register can be tweaked at once without targeting the wrong address.
+    pub async fn update_reg<R: Register>(
+        &mut self,
+        f: impl FnOnce(&mut R),
+    ) -> Result<(), Error<SPI::Error>> {
+        let mut reg = R::from_bits(self._read_register(R::ADDRESS).await.map_err(Error::Spi)?);
+        f(&mut reg);
+        let bits = reg.bits();
+        self._write_register(R::ADDRESS, bits).await?;
+        // Keep the scaling cache in sync when the gain or reference field changes.
+        match R::ADDRESS {
+            RegisterAddr::Reg0 => self.gain = Config0Reg(bits).gain(),
+            RegisterAddr::Reg2 => self.vref = Config2Reg(bits).vref_selection(),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub async fn begin(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.reset().await.map_err(Error::Spi)?;
         self.spi
             .transaction(&mut [Operation::DelayNs(50000)])
-            .await?;
+            .await
+            .map_err(Error::Spi)?;
 
         self._write_register(RegisterAddr::Reg0, 0x00) // Default settings: AINP=AIN0, AINN=AIN1, Gain 1, PGA enabled
             .await?;
@@ -259,6 +498,10 @@ impl<SPI: SpiDevice> ADS1220<SPI> {
         self._write_register(RegisterAddr::Reg2, 0x10).await?; // Default settings: Vref internal, 50/60Hz rejection, power open, IDAC off
         self._write_register(RegisterAddr::Reg3, 0x00).await?; //  Default settings: IDAC1 disabled, IDAC2 disabled, DRDY pin only
 
+        // Reflect the just-written power-on defaults in the scaling cache.
+        self.gain = PgaGain::Factor1;
+        self.vref = VrefSelect::Internal2p048;
+
         Ok(())
     }
 
@@ -286,142 +529,249 @@ impl<SPI: SpiDevice> ADS1220<SPI> {
         self.spi_command(SpiCommand::Start).await
     }
 
-    pub async fn select_mux_channels(&mut self, mux_config: AdcInputMux) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_0().await?;
-        reg.set_mux(mux_config);
-        self.write_register_0(reg).await
+    pub async fn select_mux_channels(&mut self, mux_config: AdcInputMux) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config0Reg>(|reg| reg.set_mux(mux_config))
+            .await
     }
 
-    pub async fn set_pga_gain(&mut self, pga_gain: PgaGain) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_0().await?;
-        reg.set_gain(pga_gain);
-        self.write_register_0(reg).await
+    pub async fn set_pga_gain(&mut self, pga_gain: PgaGain) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config0Reg>(|reg| reg.set_gain(pga_gain))
+            .await?;
+        // Only refresh the scaling cache once the write has actually landed.
+        self.gain = pga_gain;
+        Ok(())
     }
 
-    pub async fn set_pga_on(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_0().await?;
-        reg.set_pga_bypass(false);
-        self.write_register_0(reg).await
+    pub async fn set_pga_on(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config0Reg>(|reg| reg.set_pga_bypass(false))
+            .await
     }
 
-    pub async fn set_pga_off(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_0().await?;
-        reg.set_pga_bypass(true);
-        self.write_register_0(reg).await
+    pub async fn set_pga_off(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config0Reg>(|reg| reg.set_pga_bypass(true))
+            .await
     }
 
-    pub async fn set_data_rate(&mut self, data_rate: DataRate) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_data_rate(data_rate);
-        self.write_register_1(reg).await
+    pub async fn set_data_rate(&mut self, data_rate: DataRate) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_data_rate(data_rate))
+            .await
     }
 
-    pub async fn set_operation_mode(&mut self, mode: OperatingMode) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_operating_mode(mode);
-        self.write_register_1(reg).await
+    pub async fn set_operation_mode(&mut self, mode: OperatingMode) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_operating_mode(mode))
+            .await
     }
 
-    pub async fn set_conv_mode_single_shot(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_conversion_mode(false); // Per datasheet
-        self.write_register_1(reg).await
+    pub async fn set_conv_mode_single_shot(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_conversion_mode(false)) // Per datasheet
+            .await
     }
 
-    pub async fn set_conv_mode_continuous(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_conversion_mode(true); // Per datasheet
-        self.write_register_1(reg).await
+    pub async fn set_conv_mode_continuous(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_conversion_mode(true)) // Per datasheet
+            .await
     }
 
-    pub async fn temp_sensor_mode_disable(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_temperature_sensor_mode(false); // Per datasheet
-        self.write_register_1(reg).await
+    pub async fn temp_sensor_mode_disable(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_temperature_sensor_mode(false)) // Per datasheet
+            .await
     }
 
-    pub async fn temp_sensor_mode_enable(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_temperature_sensor_mode(true); // Per datasheet
-        self.write_register_1(reg).await
+    pub async fn temp_sensor_mode_enable(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_temperature_sensor_mode(true)) // Per datasheet
+            .await
     }
 
-    pub async fn current_sources_off(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_burn_out_current_source(false); // Per datasheet
-        self.write_register_1(reg).await
+    /// Worst-case single-shot conversion time in nanoseconds for the given data
+    /// rate and operating mode. The base is the Normal-mode `~1/DR` plus margin;
+    /// Duty-cycle runs ~16× slower and Turbo ~2× faster (and at double the data
+    /// rate), so the delay has to track the mode or a read clocks out a stale
+    /// frame.
+    fn conversion_settle_ns(data_rate: DataRate, mode: OperatingMode) -> u32 {
+        let base: u32 = match data_rate {
+            DataRate::Dr20sps => 52_000_000,
+            DataRate::Dr45sps => 24_000_000,
+            DataRate::Dr90sps => 12_000_000,
+            DataRate::Dr175sps => 6_500_000,
+            DataRate::Dr330sps => 3_500_000,
+            DataRate::Dr600sps => 2_000_000,
+            DataRate::Dr1000sps => 1_200_000,
+        };
+        match mode {
+            OperatingMode::Normal => base,
+            OperatingMode::DutyCycle => base * 16,
+            OperatingMode::Turbo => base / 2,
+        }
+    }
+
+    /// Issues START and waits for the single conversion to complete (derived
+    /// from `reg1`'s data rate and operating mode) before clocking out the three
+    /// result bytes, so callers decode the fresh frame rather than a stale one.
+    async fn converted_samples(
+        &mut self,
+        reg1: Config1Reg,
+    ) -> Result<[u8; 3], Error<SPI::Error>> {
+        let settle_ns = Self::conversion_settle_ns(reg1.data_rate(), reg1.operating_mode());
+        self.start_conv().await.map_err(Error::Spi)?;
+        self.spi
+            .transaction(&mut [Operation::DelayNs(settle_ns)])
+            .await
+            .map_err(Error::Spi)?;
+        self.read_data_samples().await.map_err(Error::Spi)
+    }
+
+    /// Reads the internal temperature sensor in degrees Celsius.
+    ///
+    /// Temporarily enables temperature-sensor mode, issues START itself and
+    /// waits for the single conversion to complete before clocking out the
+    /// frame, then decodes the ADS1220 temperature format: the 14 most
+    /// significant bits form a two's-complement value scaled by 0.03125 °C/LSB.
+    /// The previous temperature-sensor-mode bit is restored before returning so
+    /// normal analog channels keep working. Because this routine self-STARTs,
+    /// there is no "not started" state for the caller to get wrong.
+    pub async fn read_temperature(&mut self) -> Result<f32, Error<SPI::Error>> {
+        let mut reg = self.read_register_1().await.map_err(Error::Spi)?;
+        let previous = reg.temperature_sensor_mode();
+        reg.set_temperature_sensor_mode(true);
+        self.write_register_1(reg).await?;
+
+        // START and wait out the single-shot conversion (scaled by the cached
+        // data rate and operating mode) so we decode the fresh frame.
+        let sampled = self.converted_samples(reg).await;
+
+        // Restore the previous temperature-sensor-mode bit, also when the
+        // conversion failed; a conversion error takes precedence.
+        reg.set_temperature_sensor_mode(previous);
+        let restored = self.write_register_1(reg).await;
+        let data = sampled?;
+        restored?;
+
+        // The conversion is right-justified to 14 bits (code >> 10); sign-extend
+        // the 14-bit two's-complement value and scale by 0.03125 °C/LSB.
+        let code = i32::from_be_bytes([data[0], data[1], data[2], 0x00]) >> 8;
+        let temp = (code >> 10) << 18 >> 18;
+        Ok(temp as f32 * 0.03125)
+    }
+
+    pub async fn current_sources_off(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_burn_out_current_source(false)) // Per datasheet
+            .await
     }
 
-    pub async fn current_sources_on(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_1().await?;
-        reg.set_burn_out_current_source(true); // Per datasheet
-        self.write_register_1(reg).await
+    pub async fn current_sources_on(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config1Reg>(|reg| reg.set_burn_out_current_source(true)) // Per datasheet
+            .await
     }
 
-    pub async fn set_vref(&mut self, vref: VrefSelect) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_2().await?;
-        reg.set_vref_selection(vref); // Per datasheet
-        self.write_register_2(reg).await
+    pub async fn set_vref(&mut self, vref: VrefSelect) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config2Reg>(|reg| reg.set_vref_selection(vref)) // Per datasheet
+            .await?;
+        // Only refresh the scaling cache once the write has actually landed.
+        self.vref = vref;
+        Ok(())
     }
 
-    pub async fn set_fir_filter(&mut self, filter: FIRRejectionFilter) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_2().await?;
-        reg.set_fir_filter(filter); // Per datasheet
-        self.write_register_2(reg).await
+    pub async fn set_fir_filter(&mut self, filter: FIRRejectionFilter) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config2Reg>(|reg| reg.set_fir_filter(filter)) // Per datasheet
+            .await
     }
 
-    pub async fn low_side_switch_open(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_2().await?;
-        reg.set_low_side_switch(false); // Per datasheet
-        self.write_register_2(reg).await
+    pub async fn low_side_switch_open(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config2Reg>(|reg| reg.set_low_side_switch(false)) // Per datasheet
+            .await
     }
 
-    pub async fn low_side_switch_closed(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_2().await?;
-        reg.set_low_side_switch(true); // Per datasheet, this closes the switch when START/SYNC command is sent and opens when the POWERDOWN command is issued
-        self.write_register_2(reg).await
+    pub async fn low_side_switch_closed(&mut self) -> Result<(), Error<SPI::Error>> {
+        // Per datasheet, this closes the switch when START/SYNC command is sent and opens when the POWERDOWN command is issued
+        self.update_reg::<Config2Reg>(|reg| reg.set_low_side_switch(true))
+            .await
     }
 
     pub async fn set_idac_current(
         &mut self,
         idac_current: IDacSourceCurrent,
-    ) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_2().await?;
-        reg.set_idac_current_setting(idac_current);
-        self.write_register_2(reg).await
+    ) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config2Reg>(|reg| reg.set_idac_current_setting(idac_current))
+            .await
+    }
+
+    pub async fn set_idac1_route(&mut self, idac1_routing: IDacRouting) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config3Reg>(|reg| reg.set_idac1_mux(idac1_routing))
+            .await
     }
 
-    pub async fn set_idac1_route(&mut self, idac1_routing: IDacRouting) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_3().await?;
-        reg.set_idac1_mux(idac1_routing);
-        self.write_register_3(reg).await
+    pub async fn set_idac2_route(&mut self, idac2_routing: IDacRouting) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config3Reg>(|reg| reg.set_idac2_mux(idac2_routing))
+            .await
     }
 
-    pub async fn set_idac2_route(&mut self, idac2_routing: IDacRouting) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_3().await?;
-        reg.set_idac2_mux(idac2_routing);
-        self.write_register_3(reg).await
+    pub async fn set_drdy_mode_default(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config3Reg>(|reg| reg.set_drdy_mode(false))
+            .await
     }
 
-    pub async fn set_drdy_mode_default(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_3().await?;
-        reg.set_drdy_mode(false);
-        self.write_register_3(reg).await
+    pub async fn set_drdy_mode_dout(&mut self) -> Result<(), Error<SPI::Error>> {
+        self.update_reg::<Config3Reg>(|reg| reg.set_drdy_mode(true))
+            .await
     }
 
-    pub async fn set_drdy_mode_dout(&mut self) -> Result<(), SPI::Error> {
-        let mut reg = self.read_register_3().await?;
-        reg.set_drdy_mode(true);
-        self.write_register_3(reg).await
+    /// Writes a fully-built [`Config`] to the device as a single multi-byte WREG
+    /// transaction starting at Reg0, bringing the chip up atomically without
+    /// passing through the intermediate states the per-field setters would.
+    ///
+    /// When [`set_verify_writes`](ADS1220::set_verify_writes) is enabled the four
+    /// registers are read back and compared, yielding [`Error::Verify`] on the
+    /// first mismatch, so the bulk path gets the same integrity check as the
+    /// individual setters.
+    pub async fn apply(&mut self, cfg: Config) -> Result<(), Error<SPI::Error>> {
+        // WREG starting at Reg0, writing 4 registers (count field = n - 1).
+        let command = SpiCommand::WriteReg as u8 | ((RegisterAddr::Reg0 as u8) << 2) | 3;
+        let frame = [
+            command,
+            cfg.reg0.value(),
+            cfg.reg1.value(),
+            cfg.reg2.value(),
+            cfg.reg3.value(),
+        ];
+        let mut operations = [Operation::DelayNs(50), Operation::Write(&frame)];
+        self.spi
+            .transaction(&mut operations)
+            .await
+            .map_err(Error::Spi)?;
+
+        if self.verify_writes {
+            for (addr, expected) in [
+                (RegisterAddr::Reg0, cfg.reg0.value()),
+                (RegisterAddr::Reg1, cfg.reg1.value()),
+                (RegisterAddr::Reg2, cfg.reg2.value()),
+                (RegisterAddr::Reg3, cfg.reg3.value()),
+            ] {
+                let got = self._read_register(addr).await.map_err(Error::Spi)?;
+                if got != expected {
+                    return Err(Error::Verify {
+                        addr,
+                        expected,
+                        got,
+                    });
+                }
+            }
+        }
+
+        // Keep the scaling cache consistent with what we just wrote.
+        self.gain = cfg.reg0.gain();
+        self.vref = cfg.reg2.vref_selection();
+        Ok(())
     }
 
     pub async fn get_config_reg(&mut self) -> Result<[u8; 4], SPI::Error> {
-        Ok([
-            self.read_register_0().await?.value(),
-            self.read_register_1().await?.value(),
-            self.read_register_2().await?.value(),
-            self.read_register_3().await?.value(),
-        ])
+        let reg0 = self.read_register_0().await?;
+        let reg1 = self.read_register_1().await?;
+        let reg2 = self.read_register_2().await?;
+        let reg3 = self.read_register_3().await?;
+        // Refresh the scaling cache from the authoritative register contents.
+        self.gain = reg0.gain();
+        self.vref = reg2.vref_selection();
+        Ok([reg0.value(), reg1.value(), reg2.value(), reg3.value()])
     }
 
     pub async fn read_data_samples(&mut self) -> Result<[u8; 3], SPI::Error> {
@@ -435,19 +785,108 @@ impl<SPI: SpiDevice> ADS1220<SPI> {
         // extend to 32 bits
         let raw = i32::from_be_bytes([data[0], data[1], data[2], 0x00]);
         // resolve back to 24 bits, sign extension is automatic
-        raw >> 8
+        (raw >> 8) - self.offset
+    }
+
+    /// The currently applied system offset in raw codes.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    /// Sets the system offset (in raw codes) subtracted from every conversion,
+    /// e.g. to restore a value from [`calibrate_offset`](ADS1220::calibrate_offset)
+    /// that was persisted across a reset.
+    pub fn set_offset(&mut self, offset: i32) {
+        self.offset = offset;
+    }
+
+    /// Performs a system offset calibration by shorting the inputs to mid-supply
+    /// and averaging `samples` single-shot conversions at the current gain and
+    /// data rate. The mean is stored as the [`offset`](ADS1220::offset) that
+    /// subsequent conversions subtract, and the previous input mux is restored.
+    pub async fn calibrate_offset(&mut self, samples: u32) -> Result<(), Error<SPI::Error>> {
+        let saved_mux = self.read_register_0().await.map_err(Error::Spi)?.mux();
+        // Cached once so every sample waits out the full conversion.
+        let reg1 = self.read_register_1().await.map_err(Error::Spi)?;
+        self.select_mux_channels(AdcInputMux::ShortedMidSupply).await?;
+
+        // Measure with any existing offset removed so we capture the raw error.
+        let previous = self.offset;
+        self.offset = 0;
+
+        let mut acc: i64 = 0;
+        let mut sampled = Ok(());
+        for _ in 0..samples {
+            match self.converted_samples(reg1).await {
+                Ok(data) => acc += self.data_to_int(data) as i64,
+                Err(e) => {
+                    sampled = Err(e);
+                    break;
+                }
+            }
+        }
+
+        // Restore the caller's input mux on every exit path before returning.
+        let restore = self.select_mux_channels(saved_mux).await;
+
+        match sampled {
+            Ok(()) => {
+                restore?;
+                self.offset = if samples == 0 {
+                    previous
+                } else {
+                    (acc / samples as i64) as i32
+                };
+                Ok(())
+            }
+            Err(e) => {
+                // Roll back the offset we zeroed; surface the original error.
+                self.offset = previous;
+                Err(e)
+            }
+        }
+    }
+
+    /// Converts a raw 24-bit signed code to the input voltage in volts, using
+    /// the currently cached reference and PGA gain: `V = (code / 2^23) * (Vref / gain)`.
+    pub fn code_to_voltage(&self, code: i32) -> f32 {
+        let gain = (1u16 << (self.gain as u8)) as f32;
+        (code as f32 / ((1i32 << 23) as f32)) * (self.vref_volts() / gain)
+    }
+
+    /// Decodes the three conversion bytes into an input voltage in volts.
+    pub fn data_to_voltage(&mut self, data: [u8; 3]) -> f32 {
+        let code = self.data_to_int(data);
+        self.code_to_voltage(code)
+    }
+
+    pub async fn read_single_shot(&mut self) -> Result<i32, Error<SPI::Error>> {
+        self.start_conv().await.map_err(Error::Spi)?;
+        let data = self.read_data_samples().await.map_err(Error::Spi)?;
+        Ok(self.data_to_int(data))
     }
 
-    pub async fn read_single_shot(&mut self) -> Result<i32, SPI::Error> {
-        self.start_conv().await?;
-        let data = self.read_data_samples().await?;
-        Ok(self.data_to_int(data))
+    /// Performs a single-shot conversion and returns the result in volts.
+    pub async fn read_single_shot_voltage(&mut self) -> Result<f32, Error<SPI::Error>> {
+        let code = self.read_single_shot().await?;
+        Ok(self.code_to_voltage(code))
+    }
+
+    /// Performs a single-shot conversion and returns the result as a uom
+    /// [`ElectricPotential`](uom::si::f32::ElectricPotential).
+    #[cfg(feature = "uom")]
+    pub async fn read_single_shot_electric_potential(
+        &mut self,
+    ) -> Result<uom::si::f32::ElectricPotential, Error<SPI::Error>> {
+        use uom::si::electric_potential::volt;
+        let volts = self.read_single_shot_voltage().await?;
+        Ok(uom::si::f32::ElectricPotential::new::<volt>(volts))
     }
 
     pub async fn read_single_shot_from_channel(
         &mut self,
         input_mux: AdcInputMux,
-    ) -> Result<i32, SPI::Error> {
+    ) -> Result<i32, Error<SPI::Error>> {
         self.select_mux_channels(input_mux).await?;
         self.read_single_shot().await
     }